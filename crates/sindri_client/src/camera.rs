@@ -0,0 +1,73 @@
+// A minimal look-at/perspective camera and its GPU-side uniform.
+
+use cgmath::{perspective, Deg, InnerSpace, Matrix4, Point3, SquareMatrix, Vector3};
+
+/// wgpu's NDC depth range is [0, 1] rather than OpenGL's [-1, 1]; this matrix
+/// corrects cgmath's OpenGL-style projection to match.
+#[rustfmt::skip]
+const OPENGL_TO_WGPU_MATRIX: Matrix4<f32> = Matrix4::new(
+    1.0, 0.0, 0.0, 0.0,
+    0.0, 1.0, 0.0, 0.0,
+    0.0, 0.0, 0.5, 0.0,
+    0.0, 0.0, 0.5, 1.0,
+);
+
+#[derive(Debug, Clone, Copy)]
+pub struct Camera {
+    pub position: Point3<f32>,
+    pub target: Point3<f32>,
+    pub up: Vector3<f32>,
+    pub fovy: Deg<f32>,
+    pub znear: f32,
+    pub zfar: f32,
+    aspect: f32,
+}
+
+impl Camera {
+    pub fn new(position: Point3<f32>, target: Point3<f32>, aspect: f32) -> Self {
+        Camera {
+            position,
+            target,
+            up: Vector3::unit_y(),
+            fovy: Deg(45.0),
+            znear: 0.1,
+            zfar: 100.0,
+            aspect,
+        }
+    }
+
+    pub fn set_aspect(&mut self, width: u32, height: u32) {
+        self.aspect = width.max(1) as f32 / height.max(1) as f32;
+    }
+
+    fn view_proj(&self) -> Matrix4<f32> {
+        let view = Matrix4::look_at_rh(self.position, self.target, self.up.normalize());
+        let proj = perspective(self.fovy, self.aspect, self.znear, self.zfar);
+        OPENGL_TO_WGPU_MATRIX * proj * view
+    }
+}
+
+/// GPU-side mirror of `Camera`, uploaded to a uniform buffer each frame.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct CameraUniform {
+    pub view_proj: [[f32; 4]; 4],
+}
+
+impl CameraUniform {
+    pub fn new() -> Self {
+        CameraUniform {
+            view_proj: Matrix4::identity().into(),
+        }
+    }
+
+    pub fn update(&mut self, camera: &Camera) {
+        self.view_proj = camera.view_proj().into();
+    }
+}
+
+impl Default for CameraUniform {
+    fn default() -> Self {
+        Self::new()
+    }
+}
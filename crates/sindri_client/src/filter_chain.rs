@@ -0,0 +1,485 @@
+// Post-processing filter chain: an ordered list of full-screen passes that
+// sample the previous pass's output and write into their own intermediate
+// texture, RetroArch "slang preset" style. The final pass targets the
+// swapchain view directly.
+
+use std::path::{Path, PathBuf};
+
+use wgpu::util::DeviceExt;
+
+/// How large a pass's intermediate target is relative to some reference size.
+#[derive(Debug, Clone, Copy)]
+pub enum ScaleMode {
+    /// Relative to the previous pass's output size.
+    Source { scale_x: f32, scale_y: f32 },
+    /// Relative to the final viewport (surface) size.
+    Viewport { scale_x: f32, scale_y: f32 },
+    /// A fixed pixel size, independent of source/viewport.
+    Absolute { width: u32, height: u32 },
+}
+
+/// Sampling filter used when a pass reads its input texture.
+#[derive(Debug, Clone, Copy)]
+pub enum FilterMode {
+    Nearest,
+    Linear,
+}
+
+impl FilterMode {
+    fn as_wgpu(self) -> wgpu::FilterMode {
+        match self {
+            FilterMode::Nearest => wgpu::FilterMode::Nearest,
+            FilterMode::Linear => wgpu::FilterMode::Linear,
+        }
+    }
+}
+
+/// One entry of a preset file: which shader to run and how to size/sample it.
+#[derive(Debug, Clone)]
+pub struct PassConfig {
+    pub shader_path: PathBuf,
+    pub scale: ScaleMode,
+    pub filter: FilterMode,
+}
+
+/// A parsed preset: an ordered list of passes to run each frame.
+#[derive(Debug, Clone)]
+pub struct Preset {
+    pub passes: Vec<PassConfig>,
+}
+
+impl Preset {
+    /// Loads a preset from a simple TOML file:
+    ///
+    /// ```toml
+    /// [[pass]]
+    /// shader = "passes/bloom.wgsl"
+    /// scale = "source"      # source | viewport | absolute
+    /// scale_x = 1.0
+    /// scale_y = 1.0
+    /// filter = "linear"     # nearest | linear
+    /// ```
+    pub fn load(path: &Path) -> anyhow::Result<Preset> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("failed to read preset {}: {e}", path.display()))?;
+        let value: toml::Value = text.parse()?;
+
+        let base_dir = path.parent().unwrap_or_else(|| Path::new("."));
+        let mut passes = Vec::new();
+
+        let entries = value
+            .get("pass")
+            .and_then(|v| v.as_array())
+            .ok_or_else(|| anyhow::anyhow!("preset {} has no [[pass]] entries", path.display()))?;
+
+        for entry in entries {
+            let shader = entry
+                .get("shader")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| anyhow::anyhow!("pass missing `shader` in {}", path.display()))?;
+
+            // scale_x/scale_y are written as floats in "source"/"viewport" presets
+            // (e.g. 2.0) but as plain integers in "absolute" ones (e.g. 640), and
+            // toml::Value doesn't coerce between its Integer and Float variants,
+            // so both must be accepted here.
+            let scale_x = toml_number(entry.get("scale_x")).unwrap_or(1.0);
+            let scale_y = toml_number(entry.get("scale_y")).unwrap_or(1.0);
+
+            let scale = match entry.get("scale").and_then(|v| v.as_str()).unwrap_or("source") {
+                "viewport" => ScaleMode::Viewport {
+                    scale_x: scale_x as f32,
+                    scale_y: scale_y as f32,
+                },
+                "absolute" => ScaleMode::Absolute {
+                    width: scale_x as u32,
+                    height: scale_y as u32,
+                },
+                _ => ScaleMode::Source {
+                    scale_x: scale_x as f32,
+                    scale_y: scale_y as f32,
+                },
+            };
+
+            let filter = match entry.get("filter").and_then(|v| v.as_str()).unwrap_or("linear") {
+                "nearest" => FilterMode::Nearest,
+                _ => FilterMode::Linear,
+            };
+
+            passes.push(PassConfig {
+                shader_path: base_dir.join(shader),
+                scale,
+                filter,
+            });
+        }
+
+        Ok(Preset { passes })
+    }
+}
+
+/// Reads a TOML value as a number regardless of whether it was written as an
+/// Integer or a Float literal.
+fn toml_number(value: Option<&toml::Value>) -> Option<f64> {
+    value.and_then(|v| v.as_float().or_else(|| v.as_integer().map(|i| i as f64)))
+}
+
+/// Per-pass uniforms, matching the WGSL `PassUniforms` struct (group 0, binding 0).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+struct PassUniforms {
+    output_size: [f32; 2],
+    source_size: [f32; 2],
+    frame: u32,
+    _pad: [u32; 3],
+}
+
+/// An offscreen render target owned by the filter chain.
+struct Intermediate {
+    texture: wgpu::Texture,
+    view: wgpu::TextureView,
+    size: (u32, u32),
+}
+
+impl Intermediate {
+    fn new(device: &wgpu::Device, format: wgpu::TextureFormat, size: (u32, u32), label: &str) -> Self {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some(label),
+            size: wgpu::Extent3d {
+                width: size.0.max(1),
+                height: size.1.max(1),
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        Intermediate { texture, view, size }
+    }
+}
+
+/// A single compiled pass: pipeline, its input sampler and the uniform buffer
+/// that feeds `PassUniforms` into the shader.
+struct Pass {
+    pipeline: wgpu::RenderPipeline,
+    bind_group_layout: wgpu::BindGroupLayout,
+    sampler: wgpu::Sampler,
+    uniform_buffer: wgpu::Buffer,
+    scale: ScaleMode,
+}
+
+/// The full chain: one render target per pass (except the last, which targets
+/// the swapchain view directly), reallocated whenever the surface resizes.
+pub struct FilterChain {
+    passes: Vec<Pass>,
+    intermediates: Vec<Intermediate>,
+    format: wgpu::TextureFormat,
+    frame: u32,
+}
+
+impl FilterChain {
+    pub fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        preset: &Preset,
+        viewport_size: (u32, u32),
+    ) -> Self {
+        let mut passes = Vec::with_capacity(preset.passes.len());
+        for (i, cfg) in preset.passes.iter().enumerate() {
+            passes.push(Self::build_pass(device, format, cfg, i));
+        }
+
+        let mut chain = FilterChain {
+            passes,
+            intermediates: Vec::new(),
+            format,
+            frame: 0,
+        };
+        chain.resize(device, viewport_size);
+        chain
+    }
+
+    fn build_pass(device: &wgpu::Device, format: wgpu::TextureFormat, cfg: &PassConfig, index: usize) -> Pass {
+        let source = std::fs::read_to_string(&cfg.shader_path).unwrap_or_else(|e| {
+            panic!(
+                "failed to read filter pass shader {}: {e}",
+                cfg.shader_path.display()
+            )
+        });
+
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some(&format!("sindri_filter_pass_{index}_shader")),
+            source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Owned(source)),
+        });
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("sindri_filter_pass_bind_group_layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("sindri_filter_pass_pipeline_layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some(&format!("sindri_filter_pass_{index}_pipeline")),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_main",
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format,
+                    blend: Some(wgpu::BlendState::REPLACE),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        });
+
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("sindri_filter_pass_sampler"),
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            mag_filter: cfg.filter.as_wgpu(),
+            min_filter: cfg.filter.as_wgpu(),
+            ..Default::default()
+        });
+
+        let uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("sindri_filter_pass_uniforms"),
+            contents: bytemuck::bytes_of(&PassUniforms {
+                output_size: [0.0, 0.0],
+                source_size: [0.0, 0.0],
+                frame: 0,
+                _pad: [0; 3],
+            }),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        Pass {
+            pipeline,
+            bind_group_layout,
+            sampler,
+            uniform_buffer,
+            scale: cfg.scale,
+        }
+    }
+
+    fn target_size(scale: ScaleMode, source_size: (u32, u32), viewport_size: (u32, u32)) -> (u32, u32) {
+        match scale {
+            ScaleMode::Source { scale_x, scale_y } => (
+                ((source_size.0 as f32) * scale_x).max(1.0) as u32,
+                ((source_size.1 as f32) * scale_y).max(1.0) as u32,
+            ),
+            ScaleMode::Viewport { scale_x, scale_y } => (
+                ((viewport_size.0 as f32) * scale_x).max(1.0) as u32,
+                ((viewport_size.1 as f32) * scale_y).max(1.0) as u32,
+            ),
+            ScaleMode::Absolute { width, height } => (width.max(1), height.max(1)),
+        }
+    }
+
+    /// Reallocates every intermediate target except the last pass, which
+    /// always writes straight into the provided swapchain view.
+    pub fn resize(&mut self, device: &wgpu::Device, viewport_size: (u32, u32)) {
+        self.intermediates.clear();
+        if self.passes.is_empty() {
+            return;
+        }
+
+        let mut source_size = viewport_size;
+        for (i, pass) in self.passes.iter().enumerate() {
+            // The last pass writes to the surface; it has no intermediate.
+            if i + 1 == self.passes.len() {
+                break;
+            }
+            let size = Self::target_size(pass.scale, source_size, viewport_size);
+            self.intermediates.push(Intermediate::new(
+                device,
+                self.format,
+                size,
+                &format!("sindri_filter_intermediate_{i}"),
+            ));
+            source_size = size;
+        }
+    }
+
+    /// Runs every pass in order: `source_view` is the scene render, each
+    /// intermediate pass samples the previous output, and the final pass
+    /// writes into `target_view` (the swapchain view).
+    pub fn render(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        encoder: &mut wgpu::CommandEncoder,
+        source_view: &wgpu::TextureView,
+        source_size: (u32, u32),
+        target_view: &wgpu::TextureView,
+        viewport_size: (u32, u32),
+    ) {
+        if self.passes.is_empty() {
+            return;
+        }
+
+        self.frame = self.frame.wrapping_add(1);
+
+        for (i, pass) in self.passes.iter().enumerate() {
+            let is_last = i + 1 == self.passes.len();
+            let input_view = if i == 0 {
+                source_view
+            } else {
+                &self.intermediates[i - 1].view
+            };
+            let input_size = if i == 0 { source_size } else { self.intermediates[i - 1].size };
+            let output_view = if is_last { target_view } else { &self.intermediates[i].view };
+            let output_size = if is_last { viewport_size } else { self.intermediates[i].size };
+
+            queue.write_buffer(
+                &pass.uniform_buffer,
+                0,
+                bytemuck::bytes_of(&PassUniforms {
+                    output_size: [output_size.0 as f32, output_size.1 as f32],
+                    source_size: [input_size.0 as f32, input_size.1 as f32],
+                    frame: self.frame,
+                    _pad: [0; 3],
+                }),
+            );
+
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("sindri_filter_pass_bind_group"),
+                layout: &pass.bind_group_layout,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: pass.uniform_buffer.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: wgpu::BindingResource::TextureView(input_view),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 2,
+                        resource: wgpu::BindingResource::Sampler(&pass.sampler),
+                    },
+                ],
+            });
+
+            let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("sindri_filter_pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: output_view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                occlusion_query_set: None,
+                timestamp_writes: None,
+            });
+
+            rpass.set_pipeline(&pass.pipeline);
+            rpass.set_bind_group(0, &bind_group, &[]);
+            // Full-screen triangle: positions are derived from the vertex index in the shader.
+            rpass.draw(0..3, 0..1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load_str(text: &str) -> Preset {
+        let dir = std::env::temp_dir().join(format!("sindri_preset_test_{:?}", std::thread::current().id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("preset.toml");
+        std::fs::write(&path, text).unwrap();
+        Preset::load(&path).unwrap()
+    }
+
+    #[test]
+    fn absolute_scale_accepts_integer_literals() {
+        let preset = load_str(
+            r#"
+            [[pass]]
+            shader = "passes/bloom.wgsl"
+            scale = "absolute"
+            scale_x = 640
+            scale_y = 480
+            "#,
+        );
+
+        match preset.passes[0].scale {
+            ScaleMode::Absolute { width, height } => {
+                assert_eq!(width, 640);
+                assert_eq!(height, 480);
+            }
+            other => panic!("expected ScaleMode::Absolute, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn viewport_scale_accepts_integer_literals() {
+        let preset = load_str(
+            r#"
+            [[pass]]
+            shader = "passes/bloom.wgsl"
+            scale = "viewport"
+            scale_x = 2
+            scale_y = 2
+            "#,
+        );
+
+        match preset.passes[0].scale {
+            ScaleMode::Viewport { scale_x, scale_y } => {
+                assert_eq!(scale_x, 2.0);
+                assert_eq!(scale_y, 2.0);
+            }
+            other => panic!("expected ScaleMode::Viewport, got {other:?}"),
+        }
+    }
+}
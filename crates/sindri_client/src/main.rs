@@ -1,5 +1,14 @@
 // Sindri Engine - Client (renderer + input)
 
+mod camera;
+mod filter_chain;
+mod shader;
+mod texture;
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use cgmath::Point3;
 use wgpu::util::DeviceExt;
 use winit::{
     event::{Event, WindowEvent},
@@ -7,33 +16,134 @@ use winit::{
     window::{Window, WindowBuilder},
 };
 
-#[derive(Debug, Clone, Copy)]
+use camera::{Camera, CameraUniform};
+use filter_chain::{FilterChain, Preset};
+use shader::ShaderSet;
+use texture::Texture;
+
+#[cfg(target_arch = "wasm32")]
+use winit::platform::web::WindowExtWebSys;
+
+#[derive(Debug, Clone)]
 struct GpuOptions {
     fallback: bool,
     low_power: bool,
+    preset: Option<PathBuf>,
+    texture: Option<PathBuf>,
+    /// Set via `--shaders <vs> <fs>` on native, or `?vs=...&fs=...` on wasm
+    /// (the URL query string can't express a two-value flag like `--shaders`
+    /// without risking the next `key=value` pair being swallowed as `fs`).
+    shaders: Option<(PathBuf, PathBuf)>,
+    backends: wgpu::Backends,
+    adapter_index: Option<usize>,
+    list_adapters: bool,
 }
 
-fn parse_gpu_options() -> GpuOptions {
+fn parse_backends(name: &str) -> wgpu::Backends {
+    match name {
+        "vulkan" => wgpu::Backends::VULKAN,
+        "dx12" => wgpu::Backends::DX12,
+        "metal" => wgpu::Backends::METAL,
+        "gl" => wgpu::Backends::GL,
+        "all" => wgpu::Backends::all(),
+        other => panic!("unknown --backend `{other}` (expected vulkan|dx12|metal|gl|all)"),
+    }
+}
+
+/// Parses GPU options from a token stream shared by both the native CLI
+/// (`--flag value`) and the wasm URL query string, which is reduced to the
+/// same token shape before being handed here.
+fn parse_gpu_option_tokens(mut tokens: impl Iterator<Item = String>) -> GpuOptions {
     let mut opts = GpuOptions {
         fallback: false,
         low_power: false,
+        preset: None,
+        texture: None,
+        shaders: None,
+        backends: wgpu::Backends::all(),
+        adapter_index: None,
+        list_adapters: false,
     };
 
-    for arg in std::env::args().skip(1) {
+    let mut vs_path: Option<PathBuf> = None;
+    let mut fs_path: Option<PathBuf> = None;
+
+    while let Some(arg) = tokens.next() {
         match arg.as_str() {
             "--fallback-gpu" => opts.fallback = true,
             "--low-power" => opts.low_power = true,
+            "--preset" => {
+                opts.preset = tokens.next().map(PathBuf::from);
+            }
+            "--texture" => {
+                opts.texture = tokens.next().map(PathBuf::from);
+            }
+            // Native-only: consumes two positional tokens (vertex path, then
+            // fragment path). The wasm URL reducer can't express that without
+            // risking a following `--flag` token being swallowed as `fs`, so
+            // wasm callers use `--vs`/`--fs` (from `?vs=...&fs=...`) instead.
+            "--shaders" => {
+                vs_path = tokens.next().map(PathBuf::from);
+                fs_path = tokens.next().map(PathBuf::from);
+            }
+            "--vs" => {
+                vs_path = tokens.next().map(PathBuf::from);
+            }
+            "--fs" => {
+                fs_path = tokens.next().map(PathBuf::from);
+            }
+            "--backend" => {
+                opts.backends = tokens
+                    .next()
+                    .map(|name| parse_backends(&name))
+                    .unwrap_or(wgpu::Backends::all());
+            }
+            "--adapter" => {
+                opts.adapter_index = tokens.next().and_then(|v| v.parse().ok());
+            }
+            "--list-adapters" => opts.list_adapters = true,
             _ => {}
         }
     }
 
+    opts.shaders = vs_path.zip(fs_path);
     opts
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+fn parse_gpu_options() -> GpuOptions {
+    parse_gpu_option_tokens(std::env::args().skip(1))
+}
+
+/// On wasm there's no argv, so options are read from `?flag=value` pairs in
+/// the page URL instead and reduced to the same `--flag value` token shape.
+#[cfg(target_arch = "wasm32")]
+fn parse_gpu_options() -> GpuOptions {
+    let search = web_sys::window()
+        .and_then(|w| w.location().search().ok())
+        .unwrap_or_default();
+
+    let mut tokens = Vec::new();
+    for pair in search.trim_start_matches('?').split('&') {
+        if pair.is_empty() {
+            continue;
+        }
+        let mut parts = pair.splitn(2, '=');
+        let key = parts.next().unwrap_or_default();
+        tokens.push(format!("--{key}"));
+        if let Some(value) = parts.next() {
+            tokens.push(value.replace('+', " "));
+        }
+    }
+
+    parse_gpu_option_tokens(tokens.into_iter())
+}
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug, bytemuck::Pod, bytemuck::Zeroable)]
 struct Vertex {
-    pos: [f32; 2],
+    pos: [f32; 3],
+    uv: [f32; 2],
 }
 
 impl Vertex {
@@ -41,12 +151,15 @@ impl Vertex {
         wgpu::VertexBufferLayout {
             array_stride: std::mem::size_of::<Vertex>() as wgpu::BufferAddress,
             step_mode: wgpu::VertexStepMode::Vertex,
-            attributes: &wgpu::vertex_attr_array![0 => Float32x2],
+            attributes: &wgpu::vertex_attr_array![0 => Float32x3, 1 => Float32x2],
         }
     }
 }
 
 struct Gfx {
+    // The window itself isn't stored here: `Surface::<'static>` already holds
+    // its own Arc<Window> clone (see `create_surface` below), which keeps the
+    // window alive for as long as the surface needs it.
     surface: wgpu::Surface<'static>,
     device: wgpu::Device,
     queue: wgpu::Queue,
@@ -54,10 +167,50 @@ struct Gfx {
 
     render_pipeline: wgpu::RenderPipeline,
     vertex_buffer: wgpu::Buffer,
-    vertex_count: u32,
+    index_buffer: wgpu::Buffer,
+    index_count: u32,
+
+    camera: Camera,
+    camera_uniform: CameraUniform,
+    camera_buffer: wgpu::Buffer,
+    camera_bind_group: wgpu::BindGroup,
+
+    texture: Texture,
+
+    // Offscreen target the scene is rendered into before the filter chain
+    // (if any) post-processes it onto the swapchain.
+    scene_texture: wgpu::Texture,
+    scene_view: wgpu::TextureView,
+    filter_chain: Option<FilterChain>,
 }
 
 impl Gfx {
+    fn create_scene_texture(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        width: u32,
+        height: u32,
+    ) -> (wgpu::Texture, wgpu::TextureView) {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("sindri_scene_texture"),
+            size: wgpu::Extent3d {
+                width: width.max(1),
+                height: height.max(1),
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                | wgpu::TextureUsages::TEXTURE_BINDING
+                | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        (texture, view)
+    }
+
     fn resize(&mut self, width: u32, height: u32) {
         if width == 0 || height == 0 {
             return; // minimized
@@ -65,6 +218,17 @@ impl Gfx {
         self.config.width = width;
         self.config.height = height;
         self.surface.configure(&self.device, &self.config);
+
+        self.camera.set_aspect(width, height);
+
+        let (scene_texture, scene_view) =
+            Self::create_scene_texture(&self.device, self.config.format, width, height);
+        self.scene_texture = scene_texture;
+        self.scene_view = scene_view;
+
+        if let Some(chain) = &mut self.filter_chain {
+            chain.resize(&self.device, (width, height));
+        }
     }
 
     fn render(&mut self) -> Result<(), wgpu::SurfaceError> {
@@ -79,11 +243,20 @@ impl Gfx {
                 label: Some("sindri_encoder"),
             });
 
+        self.camera_uniform.update(&self.camera);
+        self.queue.write_buffer(
+            &self.camera_buffer,
+            0,
+            bytemuck::bytes_of(&self.camera_uniform),
+        );
+
+        // Scene pass: render into the offscreen source texture rather than
+        // the swapchain directly, so the filter chain has something to read.
         {
             let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("sindri_render_pass"),
                 color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: &view,
+                    view: &self.scene_view,
                     resolve_target: None,
                     ops: wgpu::Operations {
                         load: wgpu::LoadOp::Clear(wgpu::Color {
@@ -101,8 +274,35 @@ impl Gfx {
             });
 
             rpass.set_pipeline(&self.render_pipeline);
+            rpass.set_bind_group(0, &self.camera_bind_group, &[]);
+            rpass.set_bind_group(1, &self.texture.bind_group, &[]);
             rpass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
-            rpass.draw(0..self.vertex_count, 0..1);
+            rpass.set_index_buffer(self.index_buffer.slice(..), wgpu::IndexFormat::Uint16);
+            rpass.draw_indexed(0..self.index_count, 0, 0..1);
+        }
+
+        match &mut self.filter_chain {
+            Some(chain) => chain.render(
+                &self.device,
+                &self.queue,
+                &mut encoder,
+                &self.scene_view,
+                (self.config.width, self.config.height),
+                &view,
+                (self.config.width, self.config.height),
+            ),
+            None => {
+                // No preset loaded: blit the scene straight to the swapchain.
+                encoder.copy_texture_to_texture(
+                    self.scene_texture.as_image_copy(),
+                    frame.texture.as_image_copy(),
+                    wgpu::Extent3d {
+                        width: self.config.width,
+                        height: self.config.height,
+                        depth_or_array_layers: 1,
+                    },
+                );
+            }
         }
 
         self.queue.submit(Some(encoder.finish()));
@@ -111,28 +311,73 @@ impl Gfx {
     }
 }
 
-async fn init_wgpu(window: &Window, gpu_opts: GpuOptions) -> Gfx {
-    // wgpu needs the window to live long enough; we’ll safely "leak" it for now.
-    // Later we’ll wrap this more elegantly.
-    let window: &'static Window = unsafe { std::mem::transmute(window) };
+/// Prints every adapter available on the requested backends (name, device
+/// type, backend) for `--list-adapters`; used for debugging driver issues
+/// and picking a reproducible `--adapter <index>` for benchmarking.
+#[cfg(not(target_arch = "wasm32"))]
+fn list_adapters(backends: wgpu::Backends) {
+    let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
+        backends,
+        ..Default::default()
+    });
+    for (index, adapter) in instance
+        .enumerate_adapters(backends)
+        .into_iter()
+        .enumerate()
+    {
+        let info = adapter.get_info();
+        println!(
+            "[{index}] {} ({:?}) backend={:?}",
+            info.name, info.device_type, info.backend
+        );
+    }
+}
 
-    let instance = wgpu::Instance::default();
+async fn init_wgpu(window: Arc<Window>, gpu_opts: GpuOptions) -> Gfx {
+    let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
+        backends: gpu_opts.backends,
+        ..Default::default()
+    });
     let surface = instance
-        .create_surface(window)
+        .create_surface(window.clone())
         .expect("Failed to create wgpu surface");
 
-    let adapter = instance
-        .request_adapter(&wgpu::RequestAdapterOptions {
-            power_preference: if gpu_opts.low_power {
-                wgpu::PowerPreference::LowPower
-            } else {
-                wgpu::PowerPreference::HighPerformance
-            },
-            compatible_surface: Some(&surface),
-            force_fallback_adapter: gpu_opts.fallback,
-        })
-        .await
-        .expect("No suitable GPU adapters found. Try --fallback-gpu or update Vulkan drivers.");
+    // `--adapter <index>` bypasses wgpu's adapter heuristic entirely and
+    // takes whatever sits at that index in the backend's enumeration.
+    #[cfg(not(target_arch = "wasm32"))]
+    let explicit_adapter = gpu_opts.adapter_index.map(|index| {
+        let adapter = instance
+            .enumerate_adapters(gpu_opts.backends)
+            .into_iter()
+            .nth(index)
+            .unwrap_or_else(|| panic!("no adapter at --adapter {index}; see --list-adapters"));
+        if !adapter.is_surface_supported(&surface) {
+            let info = adapter.get_info();
+            panic!(
+                "adapter {index} ({}) cannot present to this surface; see --list-adapters",
+                info.name
+            );
+        }
+        adapter
+    });
+    #[cfg(target_arch = "wasm32")]
+    let explicit_adapter: Option<wgpu::Adapter> = None;
+
+    let adapter = match explicit_adapter {
+        Some(adapter) => adapter,
+        None => instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference: if gpu_opts.low_power {
+                    wgpu::PowerPreference::LowPower
+                } else {
+                    wgpu::PowerPreference::HighPerformance
+                },
+                compatible_surface: Some(&surface),
+                force_fallback_adapter: gpu_opts.fallback,
+            })
+            .await
+            .expect("No suitable GPU adapters found. Try --fallback-gpu or update Vulkan drivers."),
+    };
 
     let info = adapter.get_info();
     println!(
@@ -140,12 +385,20 @@ async fn init_wgpu(window: &Window, gpu_opts: GpuOptions) -> Gfx {
         info.name, info.device_type, info.backend
     );
 
+    // On native we request wgpu's default limits; on wasm we're bound by
+    // whatever WebGPU/WebGL2 the browser actually exposes.
+    #[cfg(not(target_arch = "wasm32"))]
+    let required_limits = wgpu::Limits::default();
+    #[cfg(target_arch = "wasm32")]
+    let required_limits =
+        wgpu::Limits::downlevel_webgl2_defaults().using_resolution(adapter.limits());
+
     let (device, queue) = adapter
         .request_device(
             &wgpu::DeviceDescriptor {
                 label: Some("sindri_device"),
                 required_features: wgpu::Features::empty(),
-                required_limits: wgpu::Limits::default(),
+                required_limits,
             },
             None,
         )
@@ -157,13 +410,20 @@ async fn init_wgpu(window: &Window, gpu_opts: GpuOptions) -> Gfx {
     let surface_caps = surface.get_capabilities(&adapter);
     let format = surface_caps
         .formats
-        .iter()
+        .first()
         .copied()
-        .find(|f| f.is_srgb())
-        .unwrap_or(surface_caps.formats[0]);
+        .map(|fallback| {
+            surface_caps
+                .formats
+                .iter()
+                .copied()
+                .find(|f| f.is_srgb())
+                .unwrap_or(fallback)
+        })
+        .expect("adapter reports no surface formats; pick a different --adapter");
 
     let config = wgpu::SurfaceConfiguration {
-        usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_DST,
         format,
         width: size.width.max(1),
         height: size.height.max(1),
@@ -179,37 +439,113 @@ async fn init_wgpu(window: &Window, gpu_opts: GpuOptions) -> Gfx {
         label: Some("sindri_triangle_shader"),
         source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Borrowed(
             r#"
+            struct CameraUniform {
+                view_proj: mat4x4<f32>,
+            }
+            @group(0) @binding(0)
+            var<uniform> camera: CameraUniform;
+
+            @group(1) @binding(0) var t_diffuse: texture_2d<f32>;
+            @group(1) @binding(1) var s_diffuse: sampler;
+
+            struct VertexOutput {
+                @builtin(position) clip_position: vec4<f32>,
+                @location(0) uv: vec2<f32>,
+            }
+
             @vertex
-            fn vs_main(@location(0) pos: vec2<f32>) -> @builtin(position) vec4<f32> {
-                return vec4<f32>(pos, 0.0, 1.0);
+            fn vs_main(@location(0) pos: vec3<f32>, @location(1) uv: vec2<f32>) -> VertexOutput {
+                var out: VertexOutput;
+                out.clip_position = camera.view_proj * vec4<f32>(pos, 1.0);
+                out.uv = uv;
+                return out;
             }
 
             @fragment
-            fn fs_main() -> @location(0) vec4<f32> {
-                return vec4<f32>(1.0, 1.0, 1.0, 1.0);
+            fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+                return textureSample(t_diffuse, s_diffuse, in.uv);
             }
             "#,
         )),
     });
 
+    let camera = Camera::new(
+        Point3::new(0.0, 0.0, 2.0),
+        Point3::new(0.0, 0.0, 0.0),
+        size.width.max(1) as f32 / size.height.max(1) as f32,
+    );
+    let camera_uniform = CameraUniform::new();
+    let camera_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+        label: Some("sindri_camera_buffer"),
+        contents: bytemuck::bytes_of(&camera_uniform),
+        usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+    });
+
+    let camera_bind_group_layout =
+        device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("sindri_camera_bind_group_layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+
+    let camera_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: Some("sindri_camera_bind_group"),
+        layout: &camera_bind_group_layout,
+        entries: &[wgpu::BindGroupEntry {
+            binding: 0,
+            resource: camera_buffer.as_entire_binding(),
+        }],
+    });
+
+    let texture_bind_group_layout = Texture::bind_group_layout(&device);
+
     let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
         label: Some("sindri_pipeline_layout"),
-        bind_group_layouts: &[],
+        bind_group_layouts: &[&camera_bind_group_layout, &texture_bind_group_layout],
         push_constant_ranges: &[],
     });
 
+    // A `--shaders <vs> <fs>` pair overrides the built-in WGSL triangle
+    // shader above; GLSL sources are compiled to SPIR-V by `ShaderSet::load`.
+    let shader_set = gpu_opts.shaders.as_ref().map(|(vs_path, fs_path)| {
+        ShaderSet::load(&device, vs_path, fs_path).unwrap_or_else(|e| {
+            panic!(
+                "failed to load shaders {} / {}: {e}",
+                vs_path.display(),
+                fs_path.display()
+            )
+        })
+    });
+    let (vertex_module, vertex_entry, fragment_module, fragment_entry) = match &shader_set {
+        Some(set) => (
+            &set.vertex,
+            set.vertex_entry.as_str(),
+            &set.fragment,
+            set.fragment_entry.as_str(),
+        ),
+        None => (&shader, "vs_main", &shader, "fs_main"),
+    };
+
     let render_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
         label: Some("sindri_triangle_pipeline"),
         layout: Some(&pipeline_layout),
         vertex: wgpu::VertexState {
-            module: &shader,
-            entry_point: "vs_main",
+            module: vertex_module,
+            entry_point: vertex_entry,
             buffers: &[Vertex::desc()],
             compilation_options: Default::default(),
         },
         fragment: Some(wgpu::FragmentState {
-            module: &shader,
-            entry_point: "fs_main",
+            module: fragment_module,
+            entry_point: fragment_entry,
             targets: &[Some(wgpu::ColorTargetState {
                 format: config.format,
                 blend: Some(wgpu::BlendState::REPLACE),
@@ -223,12 +559,21 @@ async fn init_wgpu(window: &Window, gpu_opts: GpuOptions) -> Gfx {
         multiview: None,
     });
 
-
     let vertices: &[Vertex] = &[
-        Vertex { pos: [0.0, 0.6] },
-        Vertex { pos: [-0.6, -0.6] },
-        Vertex { pos: [0.6, -0.6] },
+        Vertex {
+            pos: [0.0, 0.6, 0.0],
+            uv: [0.5, 0.0],
+        },
+        Vertex {
+            pos: [-0.6, -0.6, 0.0],
+            uv: [0.0, 1.0],
+        },
+        Vertex {
+            pos: [0.6, -0.6, 0.0],
+            uv: [1.0, 1.0],
+        },
     ];
+    let indices: &[u16] = &[0, 1, 2];
 
     let vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
         label: Some("sindri_triangle_vbo"),
@@ -236,7 +581,33 @@ async fn init_wgpu(window: &Window, gpu_opts: GpuOptions) -> Gfx {
         usage: wgpu::BufferUsages::VERTEX,
     });
 
-    let vertex_count = vertices.len() as u32;
+    let index_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+        label: Some("sindri_triangle_ibo"),
+        contents: bytemuck::cast_slice(indices),
+        usage: wgpu::BufferUsages::INDEX,
+    });
+
+    let index_count = indices.len() as u32;
+
+    let texture = match gpu_opts.texture.as_deref() {
+        Some(path) => Texture::load(&device, &queue, &texture_bind_group_layout, path)
+            .unwrap_or_else(|e| panic!("failed to load texture {}: {e}", path.display())),
+        None => Texture::white_pixel(&device, &queue, &texture_bind_group_layout),
+    };
+
+    let (scene_texture, scene_view) =
+        Gfx::create_scene_texture(&device, config.format, config.width, config.height);
+
+    let filter_chain = gpu_opts.preset.as_deref().map(|path| {
+        let preset = Preset::load(path)
+            .unwrap_or_else(|e| panic!("failed to load preset {}: {e}", path.display()));
+        FilterChain::new(
+            &device,
+            config.format,
+            &preset,
+            (config.width, config.height),
+        )
+    });
 
     Gfx {
         surface,
@@ -245,26 +616,34 @@ async fn init_wgpu(window: &Window, gpu_opts: GpuOptions) -> Gfx {
         config,
         render_pipeline,
         vertex_buffer,
-        vertex_count,
+        index_buffer,
+        index_count,
+        camera,
+        camera_uniform,
+        camera_buffer,
+        camera_bind_group,
+        texture,
+        scene_texture,
+        scene_view,
+        filter_chain,
     }
 }
 
-fn main() {
-    let gpu_opts = parse_gpu_options();
-    println!("GPU options: {:?}", gpu_opts);
-
-    if gpu_opts.fallback {
-        println!("WARNING: Running in fallback GPU mode (software renderer). Performance will be reduced.");
-    }
-
-    let event_loop = EventLoop::new().expect("Failed to create event loop");
-    let window = WindowBuilder::new()
-        .with_title("Sindri Engine")
-        .build(&event_loop)
-        .expect("Failed to create window");
-
-    let mut gfx = pollster::block_on(init_wgpu(&window, gpu_opts));
+/// Attaches the window's canvas to the `#sindri-canvas` element in the host
+/// page so the renderer has somewhere to draw on the web.
+#[cfg(target_arch = "wasm32")]
+fn attach_canvas(window: &Window) {
+    web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|doc| {
+            let dst = doc.get_element_by_id("sindri-canvas")?;
+            let canvas = web_sys::Element::from(window.canvas()?);
+            dst.append_child(&canvas).ok()
+        })
+        .expect("Couldn't append canvas to #sindri-canvas element");
+}
 
+fn run_event_loop(event_loop: EventLoop<()>, window: Arc<Window>, mut gfx: Gfx) {
     event_loop
         .run(move |event, elwt| {
             elwt.set_control_flow(ControlFlow::Poll);
@@ -290,3 +669,46 @@ fn main() {
         })
         .expect("Event loop crashed");
 }
+
+fn main() {
+    let gpu_opts = parse_gpu_options();
+    println!("GPU options: {:?}", gpu_opts);
+
+    #[cfg(not(target_arch = "wasm32"))]
+    if gpu_opts.list_adapters {
+        list_adapters(gpu_opts.backends);
+        return;
+    }
+
+    if gpu_opts.fallback {
+        println!("WARNING: Running in fallback GPU mode (software renderer). Performance will be reduced.");
+    }
+
+    let event_loop = EventLoop::new().expect("Failed to create event loop");
+    let window = Arc::new(
+        WindowBuilder::new()
+            .with_title("Sindri Engine")
+            .build(&event_loop)
+            .expect("Failed to create window"),
+    );
+
+    #[cfg(target_arch = "wasm32")]
+    attach_canvas(&window);
+
+    // Native can block on adapter/device setup; wasm must not block the
+    // main thread, so initialization continues on a spawned future and the
+    // event loop only starts once it resolves.
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let gfx = pollster::block_on(init_wgpu(window.clone(), gpu_opts));
+        run_event_loop(event_loop, window, gfx);
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        wasm_bindgen_futures::spawn_local(async move {
+            let gfx = init_wgpu(window.clone(), gpu_opts).await;
+            run_event_loop(event_loop, window, gfx);
+        });
+    }
+}
@@ -0,0 +1,96 @@
+// Shader ingestion: WGSL loads straight through (its entry point is
+// discovered by parsing the module, not assumed), while GLSL (.vert/.frag/
+// .glsl) is compiled to SPIR-V via naga's GLSL frontend first. A stepping
+// stone towards importing RetroArch-style `.slang` sources later.
+
+use std::borrow::Cow;
+use std::path::Path;
+
+use anyhow::Context;
+
+/// A compiled vertex + fragment module pair, with the entry points each was
+/// compiled with (GLSL always uses `main`; for WGSL the source's own entry
+/// point name is discovered by parsing the module, so it isn't required to
+/// be named `vs_main`/`fs_main`).
+pub struct ShaderSet {
+    pub vertex: wgpu::ShaderModule,
+    pub vertex_entry: String,
+    pub fragment: wgpu::ShaderModule,
+    pub fragment_entry: String,
+}
+
+impl ShaderSet {
+    pub fn load(device: &wgpu::Device, vertex_path: &Path, fragment_path: &Path) -> anyhow::Result<ShaderSet> {
+        let (vertex, vertex_entry) = load_module(device, vertex_path, naga::ShaderStage::Vertex)?;
+        let (fragment, fragment_entry) = load_module(device, fragment_path, naga::ShaderStage::Fragment)?;
+        Ok(ShaderSet {
+            vertex,
+            vertex_entry,
+            fragment,
+            fragment_entry,
+        })
+    }
+}
+
+fn load_module(
+    device: &wgpu::Device,
+    path: &Path,
+    stage: naga::ShaderStage,
+) -> anyhow::Result<(wgpu::ShaderModule, String)> {
+    let source = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read shader {}", path.display()))?;
+    let label = format!("sindri_shader_{}", path.display());
+
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("wgsl") => {
+            let entry_point = wgsl_entry_point(&source, stage, path)?;
+            let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some(&label),
+                source: wgpu::ShaderSource::Wgsl(Cow::Owned(source)),
+            });
+            Ok((module, entry_point))
+        }
+        Some("vert") | Some("frag") | Some("glsl") => {
+            let spirv = glsl_to_spirv(&source, stage, path)?;
+            let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some(&label),
+                source: wgpu::ShaderSource::SpirV(Cow::Owned(spirv)),
+            });
+            // naga's GLSL frontend always names the entry point `main`.
+            Ok((module, "main".to_string()))
+        }
+        other => anyhow::bail!(
+            "unsupported shader extension `{:?}` for {}",
+            other,
+            path.display()
+        ),
+    }
+}
+
+/// Finds the name of `source`'s entry point for `stage` by parsing it, rather
+/// than assuming the conventional `vs_main`/`fs_main` naming.
+fn wgsl_entry_point(source: &str, stage: naga::ShaderStage, path: &Path) -> anyhow::Result<String> {
+    let module = naga::front::wgsl::parse_str(source)
+        .map_err(|e| anyhow::anyhow!("failed to parse WGSL shader {}: {e}", path.display()))?;
+
+    module
+        .entry_points
+        .iter()
+        .find(|ep| ep.stage == stage)
+        .map(|ep| ep.name.clone())
+        .ok_or_else(|| anyhow::anyhow!("WGSL shader {} has no {stage:?} entry point", path.display()))
+}
+
+fn glsl_to_spirv(source: &str, stage: naga::ShaderStage, path: &Path) -> anyhow::Result<Vec<u32>> {
+    let options = naga::front::glsl::Options::from(stage);
+    let module = naga::front::glsl::Frontend::default()
+        .parse(&options, source)
+        .map_err(|e| anyhow::anyhow!("failed to parse GLSL shader {}: {e:?}", path.display()))?;
+
+    let info = naga::valid::Validator::new(naga::valid::ValidationFlags::all(), naga::valid::Capabilities::all())
+        .validate(&module)
+        .map_err(|e| anyhow::anyhow!("invalid shader module {}: {e}", path.display()))?;
+
+    naga::back::spv::write_vec(&module, &info, &naga::back::spv::Options::default(), None)
+        .map_err(|e| anyhow::anyhow!("failed to emit SPIR-V for {}: {e}", path.display()))
+}